@@ -6,10 +6,9 @@ use http_io::error::Result;
 use http_io::url::Url;
 
 fn main() -> Result<()> {
-    let args = std::env::args();
+    let mut args = std::env::args();
     let url: Url = args
-        .skip(1)
-        .next()
+        .nth(1)
         .unwrap_or("http://www.google.com".into())
         .parse()?;
 
@@ -0,0 +1,40 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+    UrlError(String),
+    IoError(String),
+    TooManyRedirects,
+    UnsupportedTransport(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UrlError(s) => write!(f, "url error: {}", s),
+            Error::IoError(s) => write!(f, "io error: {}", s),
+            Error::TooManyRedirects => write!(f, "too many redirects"),
+            Error::UnsupportedTransport(s) => write!(f, "unsupported transport: {}", s),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::IoError(err.to_string())
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(err: url::ParseError) -> Self {
+        Error::UrlError(err.to_string())
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;